@@ -0,0 +1,89 @@
+use yew::prelude::*;
+
+/// Shared state for an expandable [Card](super::Card), provided via
+/// [ContextProvider] so descendants can read or toggle the expanded state
+/// without the caller having to wire it through manually.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardContext {
+    /// Whether the card's expandable content is currently shown.
+    pub expanded: bool,
+    /// Flips [CardContext::expanded] when called.
+    pub toggle: Callback<()>,
+}
+
+/// # Properties of [CardExpandableContent]
+#[derive(Properties, Debug, PartialEq)]
+pub struct CardExpandableContentProps {
+    /// Inner components (displayed when the card is expanded)
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Card Expandable Content component
+/// Content hidden inside an expandable [Card](super::Card) until it is expanded,
+/// e.g. by a [CardExpandToggle]. Reads its [CardContext] from the enclosing
+/// [Card](super::Card), so it must be rendered somewhere inside one with
+/// `expandable` set.
+///
+/// See [CardExpandableContentProps] for a list of properties.
+#[function_component]
+pub fn CardExpandableContent(props: &CardExpandableContentProps) -> Html {
+    let expanded = use_context::<CardContext>()
+        .map(|context| context.expanded)
+        .unwrap_or(false);
+
+    if !expanded {
+        return html! {};
+    }
+
+    let mut classes = Classes::from("collapse");
+    classes.push("show");
+    classes.push("card-body");
+    classes.extend(&props.class);
+
+    html! {
+        <div class={classes}>
+            {props.children.clone()}
+        </div>
+    }
+}
+
+/// # Properties of [CardExpandToggle]
+#[derive(Properties, Debug, PartialEq)]
+pub struct CardExpandToggleProps {
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Card Expand Toggle component
+/// A caret button, typically placed in a [CardHeader](super::CardHeader), that
+/// flips the enclosing [Card](super::Card)'s expanded state when clicked. Reads
+/// its [CardContext] from the enclosing [Card](super::Card), so it must be
+/// rendered somewhere inside one with `expandable` set.
+///
+/// See [CardExpandToggleProps] for a list of properties.
+#[function_component]
+pub fn CardExpandToggle(props: &CardExpandToggleProps) -> Html {
+    let context = use_context::<CardContext>();
+    let expanded = context.as_ref().map(|context| context.expanded).unwrap_or(false);
+
+    let onclick = Callback::from(move |_| {
+        if let Some(context) = &context {
+            context.toggle.emit(());
+        }
+    });
+
+    let mut classes = Classes::from("btn");
+    classes.push("btn-sm");
+    classes.extend(&props.class);
+
+    html! {
+        <button type="button" class={classes} onclick={onclick} aria-expanded={expanded.to_string()}>
+            <i class={if expanded { "bi bi-caret-up-fill" } else { "bi bi-caret-down-fill" }}></i>
+        </button>
+    }
+}
@@ -1,5 +1,7 @@
 use yew::prelude::*;
 
+use crate::util::{calculate_classes, Border, BorderColor, Margin};
+
 /// Controls the display variant used for a [CardImage]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ImageVariant {
@@ -11,6 +13,31 @@ pub enum ImageVariant {
     Bottom,
 }
 
+/// Controls how a [CardImage] fills the box given by its `width`/`height`,
+/// mirroring the CSS `object-fit` property.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ObjectFit {
+    /// Scales to cover the box, cropping if needed (`object-fit: cover`).
+    Cover,
+    /// Scales to fit inside the box, letterboxing if needed (`object-fit: contain`).
+    Contain,
+    /// Stretches to fill the box, ignoring aspect ratio (`object-fit: fill`).
+    Fill,
+    /// Ignores the box and renders at the image's natural size (`object-fit: none`).
+    None,
+}
+
+impl ObjectFit {
+    fn css_class(&self) -> &'static str {
+        match self {
+            ObjectFit::Cover => "object-fit-cover",
+            ObjectFit::Contain => "object-fit-contain",
+            ObjectFit::Fill => "object-fit-fill",
+            ObjectFit::None => "object-fit-none",
+        }
+    }
+}
+
 /// # Properties of [CardImage]
 #[derive(Properties, Debug, PartialEq)]
 pub struct CardImageProps {
@@ -25,6 +52,35 @@ pub struct CardImageProps {
     /// Descriptive text for screen reader users.
     #[prop_or_default]
     pub alt: AttrValue,
+    /// A single margin utility to apply, e.g. `Margin(Edge::Bottom, 3)`.
+    #[prop_or_default]
+    pub margin: Option<Margin>,
+    /// Multiple margin utilities to apply.
+    #[prop_or_default]
+    pub margins: Vec<Margin>,
+    /// A single border utility to apply, e.g. `Border(Edge::Top)`.
+    #[prop_or_default]
+    pub border: Option<Border>,
+    /// Multiple border utilities to apply.
+    #[prop_or_default]
+    pub borders: Vec<Border>,
+    /// The color of the image's border, e.g. `BorderColor(Color::Primary)`.
+    #[prop_or_default]
+    pub border_color: Option<BorderColor>,
+    /// The image's width, in pixels. Defaults to `100%` of the card when unset.
+    #[prop_or_default]
+    pub width: Option<u32>,
+    /// The image's height, in pixels. Defaults to `180` to match the card's
+    /// previous fixed height; pass `None` to size to the image's content instead.
+    #[prop_or(Some(180))]
+    pub height: Option<u32>,
+    /// How the image should fill its `width`/`height` box. See [ObjectFit].
+    #[prop_or_default]
+    pub object_fit: Option<ObjectFit>,
+    /// When `true`, the browser is hinted to defer loading the image until it
+    /// nears the viewport via the standard `loading="lazy"` attribute.
+    #[prop_or_default]
+    pub lazy: bool,
 }
 
 /// # Card Image component
@@ -41,9 +97,30 @@ pub fn CardImage(props: &CardImageProps) -> Html {
     };
 
     classes.extend(&props.class);
+    classes.extend(calculate_classes(
+        props.margin,
+        &props.margins,
+        props.border,
+        &props.borders,
+        props.border_color,
+    ));
+    if let Some(object_fit) = props.object_fit {
+        classes.push(object_fit.css_class());
+    }
+
+    let mut style = match props.width {
+        Some(width) => format!("width: {width}px;"),
+        None => "width: 100%;".to_string(),
+    };
+    if let Some(height) = props.height {
+        style.push_str(&format!(" height: {height}px;"));
+    }
+    style.push_str(" display: block;");
+
+    let loading = props.lazy.then_some("lazy");
 
     html! {
-        <img class={classes} data-src={props.src.clone()} style="height: 180px; width: 100%; display: block;" alt={props.alt.clone()} />
+        <img class={classes} src={props.src.clone()} loading={loading} style={style} alt={props.alt.clone()} />
     }
 }
 
@@ -56,6 +133,21 @@ pub struct CardImageOverlayProps {
     /// Extra CSS classes to include, in addition to the defaults.
     #[prop_or_default]
     pub class: Classes,
+    /// A single margin utility to apply, e.g. `Margin(Edge::Bottom, 3)`.
+    #[prop_or_default]
+    pub margin: Option<Margin>,
+    /// Multiple margin utilities to apply.
+    #[prop_or_default]
+    pub margins: Vec<Margin>,
+    /// A single border utility to apply, e.g. `Border(Edge::Top)`.
+    #[prop_or_default]
+    pub border: Option<Border>,
+    /// Multiple border utilities to apply.
+    #[prop_or_default]
+    pub borders: Vec<Border>,
+    /// The color of the overlay's border, e.g. `BorderColor(Color::Primary)`.
+    #[prop_or_default]
+    pub border_color: Option<BorderColor>,
 }
 
 /// # Card Image Overlay component
@@ -81,6 +173,13 @@ pub struct CardImageOverlayProps {
 pub fn CardImageOverlay(props: &CardImageOverlayProps) -> Html {
     let mut classes = props.class.clone();
     classes.push("card-img-overlay");
+    classes.extend(calculate_classes(
+        props.margin,
+        &props.margins,
+        props.border,
+        &props.borders,
+        props.border_color,
+    ));
 
     html! {
         <div class={classes}>
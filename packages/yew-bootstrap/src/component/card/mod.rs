@@ -0,0 +1,114 @@
+use yew::prelude::*;
+
+use crate::util::{calculate_classes, Border, BorderColor, Margin};
+
+mod context;
+pub use context::*;
+
+mod image;
+pub use image::*;
+
+mod parts;
+pub use parts::*;
+
+/// # Properties of [Card]
+#[derive(Properties, Debug, PartialEq)]
+pub struct CardProps {
+    /// Inner components (typically [CardHeader], [CardImage], [CardBody], etc.)
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+    /// Whether this card provides a [CardContext] so descendants such as
+    /// [CardExpandableContent] and [CardExpandToggle] can show/hide content.
+    #[prop_or_default]
+    pub expandable: bool,
+    /// The initial value of the card's expanded state. Only meaningful when
+    /// `expandable` is `true`.
+    #[prop_or_default]
+    pub default_expanded: bool,
+    /// A single margin utility to apply, e.g. `Margin(Edge::Bottom, 3)`.
+    #[prop_or_default]
+    pub margin: Option<Margin>,
+    /// Multiple margin utilities to apply, e.g. `[Margin(Edge::X, 2), Margin(Edge::Y, 1)]`.
+    #[prop_or_default]
+    pub margins: Vec<Margin>,
+    /// A single border utility to apply, e.g. `Border(Edge::Top)`.
+    #[prop_or_default]
+    pub border: Option<Border>,
+    /// Multiple border utilities to apply.
+    #[prop_or_default]
+    pub borders: Vec<Border>,
+    /// The color of the card's border, e.g. `BorderColor(Color::Primary)`.
+    #[prop_or_default]
+    pub border_color: Option<BorderColor>,
+}
+
+/// # Card component
+/// The root of a Bootstrap card. Renders its children inside a `div.card`.
+///
+/// Set `expandable` to provide a [CardContext] to descendants, letting
+/// [CardExpandableContent] and [CardExpandToggle] build accordions/disclosure
+/// cards without manually wiring up the toggle state yourself.
+///
+/// See [CardProps] for a list of properties.
+///
+/// ## Examples
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::card::*;
+/// fn test() -> Html {
+///   html! {
+///     <Card expandable=true>
+///         <CardHeader>
+///             {"Title"}
+///             <CardExpandToggle />
+///         </CardHeader>
+///         <CardExpandableContent>
+///             {"Only shown when expanded"}
+///         </CardExpandableContent>
+///     </Card>
+///   }
+/// }
+/// ```
+#[function_component]
+pub fn Card(props: &CardProps) -> Html {
+    let expanded = use_state(|| props.default_expanded);
+    let toggle = {
+        let expanded = expanded.clone();
+        Callback::from(move |_| expanded.set(!*expanded))
+    };
+
+    let mut classes = Classes::from("card");
+    classes.extend(&props.class);
+    classes.extend(calculate_classes(
+        props.margin,
+        &props.margins,
+        props.border,
+        &props.borders,
+        props.border_color,
+    ));
+
+    let children = html! {
+        <div class={classes}>
+            {props.children.clone()}
+        </div>
+    };
+
+    if !props.expandable {
+        return children;
+    }
+
+    let context = CardContext {
+        expanded: *expanded,
+        toggle,
+    };
+
+    html! {
+        <ContextProvider<CardContext> context={context}>
+            {children}
+        </ContextProvider<CardContext>>
+    }
+}
@@ -0,0 +1,223 @@
+use yew::prelude::*;
+
+/// # Properties of [CardHeader]
+#[derive(Properties, Debug, PartialEq)]
+pub struct CardHeaderProps {
+    /// Inner components.
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Card Header component
+/// A header for a [Card](super::Card), rendered as a `div.card-header`.
+///
+/// See [CardHeaderProps] for a list of properties.
+#[function_component]
+pub fn CardHeader(props: &CardHeaderProps) -> Html {
+    let mut classes = Classes::from("card-header");
+    classes.extend(&props.class);
+
+    html! {
+        <div class={classes}>
+            {props.children.clone()}
+        </div>
+    }
+}
+
+/// # Properties of [CardBody]
+#[derive(Properties, Debug, PartialEq)]
+pub struct CardBodyProps {
+    /// Inner components.
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Card Body component
+/// The main content area of a [Card](super::Card), rendered as a `div.card-body`.
+///
+/// See [CardBodyProps] for a list of properties.
+#[function_component]
+pub fn CardBody(props: &CardBodyProps) -> Html {
+    let mut classes = Classes::from("card-body");
+    classes.extend(&props.class);
+
+    html! {
+        <div class={classes}>
+            {props.children.clone()}
+        </div>
+    }
+}
+
+/// # Properties of [CardFooter]
+#[derive(Properties, Debug, PartialEq)]
+pub struct CardFooterProps {
+    /// Inner components.
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Card Footer component
+/// A footer for a [Card](super::Card), rendered as a `div.card-footer`.
+///
+/// See [CardFooterProps] for a list of properties.
+#[function_component]
+pub fn CardFooter(props: &CardFooterProps) -> Html {
+    let mut classes = Classes::from("card-footer");
+    classes.extend(&props.class);
+
+    html! {
+        <div class={classes}>
+            {props.children.clone()}
+        </div>
+    }
+}
+
+/// # Properties of [CardTitle]
+#[derive(Properties, Debug, PartialEq)]
+pub struct CardTitleProps {
+    /// Inner components.
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Card Title component
+/// A [Card](super::Card)'s title, rendered as an `h5.card-title`.
+///
+/// See [CardTitleProps] for a list of properties.
+#[function_component]
+pub fn CardTitle(props: &CardTitleProps) -> Html {
+    let mut classes = Classes::from("card-title");
+    classes.extend(&props.class);
+
+    html! {
+        <h5 class={classes}>
+            {props.children.clone()}
+        </h5>
+    }
+}
+
+/// # Properties of [CardSubtitle]
+#[derive(Properties, Debug, PartialEq)]
+pub struct CardSubtitleProps {
+    /// Inner components.
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Card Subtitle component
+/// A [Card](super::Card)'s subtitle, rendered as an `h6.card-subtitle`.
+///
+/// See [CardSubtitleProps] for a list of properties.
+#[function_component]
+pub fn CardSubtitle(props: &CardSubtitleProps) -> Html {
+    let mut classes = Classes::from("card-subtitle");
+    classes.extend(&props.class);
+
+    html! {
+        <h6 class={classes}>
+            {props.children.clone()}
+        </h6>
+    }
+}
+
+/// # Properties of [CardText]
+#[derive(Properties, Debug, PartialEq)]
+pub struct CardTextProps {
+    /// Inner components.
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Card Text component
+/// A paragraph of body text within a [Card](super::Card), rendered as a `p.card-text`.
+///
+/// See [CardTextProps] for a list of properties.
+#[function_component]
+pub fn CardText(props: &CardTextProps) -> Html {
+    let mut classes = Classes::from("card-text");
+    classes.extend(&props.class);
+
+    html! {
+        <p class={classes}>
+            {props.children.clone()}
+        </p>
+    }
+}
+
+/// # Properties of [CardLink]
+#[derive(Properties, Debug, PartialEq)]
+pub struct CardLinkProps {
+    /// Inner components.
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+    /// The link's target, passed directly to the generated `a`'s `href`.
+    #[prop_or_default]
+    pub href: AttrValue,
+}
+
+/// # Card Link component
+/// A link within a [Card](super::Card), rendered as an `a.card-link`.
+///
+/// See [CardLinkProps] for a list of properties.
+#[function_component]
+pub fn CardLink(props: &CardLinkProps) -> Html {
+    let mut classes = Classes::from("card-link");
+    classes.extend(&props.class);
+
+    html! {
+        <a class={classes} href={props.href.clone()}>
+            {props.children.clone()}
+        </a>
+    }
+}
+
+/// # Properties of [CardListGroup]
+#[derive(Properties, Debug, PartialEq)]
+pub struct CardListGroupProps {
+    /// The list rows, typically plain `<li class="list-group-item">` items.
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Card List Group component
+/// Lets a [Card](super::Card) host a flush list group, rendered as a
+/// `ul.list-group.list-group-flush` alongside the card's other sections (e.g.
+/// between a [CardHeader] and [CardFooter]).
+///
+/// See [CardListGroupProps] for a list of properties.
+#[function_component]
+pub fn CardListGroup(props: &CardListGroupProps) -> Html {
+    let mut classes = Classes::from("list-group");
+    classes.push("list-group-flush");
+    classes.extend(&props.class);
+
+    html! {
+        <ul class={classes}>
+            {props.children.clone()}
+        </ul>
+    }
+}
@@ -0,0 +1,46 @@
+/// A Bootstrap contextual color, used to build utility classes such as
+/// `text-primary`, `bg-success` or `border-danger`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Color {
+    /// The theme's primary brand color.
+    Primary,
+    /// The theme's secondary brand color.
+    Secondary,
+    /// Indicates a successful or positive action.
+    Success,
+    /// Indicates a dangerous or negative action.
+    Danger,
+    /// Indicates a cautionary state that may need attention.
+    Warning,
+    /// Indicates a neutral, informative message.
+    Info,
+    /// A light, typically near-white, color.
+    Light,
+    /// A dark, typically near-black, color.
+    Dark,
+    /// The page body's default text color.
+    Body,
+    /// Plain white.
+    White,
+    /// No color; transparent.
+    Transparent,
+}
+
+impl Color {
+    /// The Bootstrap suffix used when building utility classes, e.g. `"primary"`.
+    pub fn css_name(&self) -> &'static str {
+        match self {
+            Color::Primary => "primary",
+            Color::Secondary => "secondary",
+            Color::Success => "success",
+            Color::Danger => "danger",
+            Color::Warning => "warning",
+            Color::Info => "info",
+            Color::Light => "light",
+            Color::Dark => "dark",
+            Color::Body => "body",
+            Color::White => "white",
+            Color::Transparent => "transparent",
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! Shared helpers for translating typed styling values into Bootstrap
+//! utility classes.
+
+mod color;
+pub use color::*;
+
+mod spacing;
+pub use spacing::*;
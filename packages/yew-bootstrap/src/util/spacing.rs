@@ -0,0 +1,145 @@
+use std::fmt;
+
+use yew::Classes;
+
+use super::Color;
+
+/// A side (or every side) of a box, used by [Margin] and [Border] to pick
+/// which Bootstrap spacing/border utility to generate.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Edge {
+    /// All four sides, e.g. `m-3` or `border`.
+    All,
+    /// The top side, e.g. `mt-3` or `border-top`.
+    Top,
+    /// The trailing side (right in LTR), e.g. `me-3` or `border-end`.
+    End,
+    /// The bottom side, e.g. `mb-3` or `border-bottom`.
+    Bottom,
+    /// The leading side (left in LTR), e.g. `ms-3` or `border-start`.
+    Start,
+    /// Both the leading and trailing sides, e.g. `mx-3`.
+    X,
+    /// Both the top and bottom sides, e.g. `my-3`.
+    Y,
+}
+
+/// A Bootstrap margin utility. `Margin(Edge::Bottom, 2)` becomes the class `mb-2`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Margin(pub Edge, pub u8);
+
+impl fmt::Display for Margin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let infix = match self.0 {
+            Edge::All => "",
+            Edge::Top => "t",
+            Edge::End => "e",
+            Edge::Bottom => "b",
+            Edge::Start => "s",
+            Edge::X => "x",
+            Edge::Y => "y",
+        };
+        write!(f, "m{}-{}", infix, self.1)
+    }
+}
+
+/// A Bootstrap border utility. `Border(Edge::Top)` becomes the class
+/// `border-top`; `Border(Edge::All)` becomes plain `border`. Bootstrap has no
+/// single `border-x`/`border-y` utility, so `Border(Edge::X)`/`Border(Edge::Y)`
+/// expand to the pair of side classes they represent (e.g. `border-start
+/// border-end`) rather than silently falling back to a border on all sides.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Border(pub Edge);
+
+impl fmt::Display for Border {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Edge::All => write!(f, "border"),
+            Edge::Top => write!(f, "border-top"),
+            Edge::End => write!(f, "border-end"),
+            Edge::Bottom => write!(f, "border-bottom"),
+            Edge::Start => write!(f, "border-start"),
+            Edge::X => write!(f, "border-start border-end"),
+            Edge::Y => write!(f, "border-top border-bottom"),
+        }
+    }
+}
+
+/// A Bootstrap border color utility. `BorderColor(Color::Primary)` becomes the
+/// class `border-primary`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BorderColor(pub Color);
+
+impl fmt::Display for BorderColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "border-{}", self.0.css_name())
+    }
+}
+
+/// Merges the typed margin/border utility props shared by the card components
+/// into a single space-joined [Classes] list.
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_classes(
+    margin: Option<Margin>,
+    margins: &[Margin],
+    border: Option<Border>,
+    borders: &[Border],
+    border_color: Option<BorderColor>,
+) -> Classes {
+    let mut classes = Classes::new();
+    classes.extend(margin.map(|value| value.to_string()));
+    classes.extend(margins.iter().map(Margin::to_string));
+    classes.extend(border.map(|value| value.to_string()));
+    classes.extend(borders.iter().map(Border::to_string));
+    classes.extend(border_color.map(|value| value.to_string()));
+    classes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn margin_formats_single_and_combined_edges() {
+        assert_eq!(Margin(Edge::All, 3).to_string(), "m-3");
+        assert_eq!(Margin(Edge::Bottom, 2).to_string(), "mb-2");
+        assert_eq!(Margin(Edge::X, 2).to_string(), "mx-2");
+        assert_eq!(Margin(Edge::Y, 1).to_string(), "my-1");
+    }
+
+    #[test]
+    fn border_formats_single_sides() {
+        assert_eq!(Border(Edge::All).to_string(), "border");
+        assert_eq!(Border(Edge::Top).to_string(), "border-top");
+        assert_eq!(Border(Edge::End).to_string(), "border-end");
+        assert_eq!(Border(Edge::Bottom).to_string(), "border-bottom");
+        assert_eq!(Border(Edge::Start).to_string(), "border-start");
+    }
+
+    #[test]
+    fn border_x_and_y_apply_only_two_sides_not_all_four() {
+        assert_eq!(Border(Edge::X).to_string(), "border-start border-end");
+        assert_eq!(Border(Edge::Y).to_string(), "border-top border-bottom");
+    }
+
+    #[test]
+    fn border_color_formats() {
+        assert_eq!(BorderColor(Color::Primary).to_string(), "border-primary");
+    }
+
+    #[test]
+    fn calculate_classes_merges_all_utility_values_in_order() {
+        let classes = calculate_classes(
+            Some(Margin(Edge::Bottom, 2)),
+            &[Margin(Edge::Top, 1)],
+            Some(Border(Edge::All)),
+            &[Border(Edge::Top)],
+            Some(BorderColor(Color::Primary)),
+        );
+
+        assert_eq!(
+            classes,
+            Classes::from(vec!["mb-2", "mt-1", "border", "border-top", "border-primary"])
+        );
+    }
+}